@@ -1,11 +1,18 @@
 pub use std::collections::{HashSet, HashMap};
+use std::cell::RefCell;
 
-use super::{Data, Static, Bool, Vector, Map};
+use super::{Data, Static, Bool, Vector, Map, Lambda, SectionLambda, StrAllocating};
 
 pub struct Builder<'a> {
     data: HashMap<String, Data<'a>>,
 }
 
+impl<'a> Default for Builder<'a> {
+    fn default() -> Builder<'a> {
+        Builder::new()
+    }
+}
+
 impl<'a> Builder<'a> {
     pub fn new() -> Builder<'a> {
         Builder {
@@ -16,20 +23,69 @@ impl<'a> Builder<'a> {
     pub fn insert_static<K: StrAllocating, V: StrAllocating>(self, key: K, value: V) -> Builder<'a> {
         let Builder { mut data } = self;
         data.insert(key.into_string(), Static(value.into_string()));
-        Builder { data: data }
+        Builder { data }
+    }
+
+    // Shorthand for `insert_static`, matching the name the spec tests already use.
+    pub fn insert<K: StrAllocating, V: StrAllocating>(self, key: K, value: V) -> Builder<'a> {
+        self.insert_static(key, value)
     }
 
     pub fn insert_bool<K: StrAllocating>(self, key: K, value: bool) -> Builder<'a> {
         let Builder { mut data } = self;
         data.insert(key.into_string(), Bool(value));
-        Builder { data: data }
+        Builder { data }
+    }
+
+    // Hands the closure a throwaway `Builder` and stores the `Vec<Data>` the
+    // closure returns as plain Vector data.
+    pub fn insert_vector<K: StrAllocating, F: FnOnce(Builder<'a>) -> Vec<Data<'a>>>(self, key: K, f: F) -> Builder<'a> {
+        let Builder { mut data } = self;
+        let items = f(Builder::new());
+        data.insert(key.into_string(), Vector(items));
+        Builder { data }
+    }
+
+    pub fn insert_map<K: StrAllocating, F: FnOnce(Builder<'a>) -> Builder<'a>>(self, key: K, f: F) -> Builder<'a> {
+        let Builder { mut data } = self;
+        let sub = f(Builder::new());
+        data.insert(key.into_string(), Map(sub.data));
+        Builder { data }
+    }
+
+    // Interpolation lambdas are arity-0: the spec requires `{{lambda}}` to
+    // call the lambda with no arguments and re-parse its result against the
+    // default delimiters.
+    pub fn insert_lambda<K: StrAllocating>(self, key: K, f: &'a mut dyn FnMut() -> String) -> Builder<'a> {
+        let Builder { mut data } = self;
+        data.insert(key.into_string(), Lambda(RefCell::new(f)));
+        Builder { data }
+    }
+
+    // Section lambdas are arity-1: the spec requires `{{#lambda}}...{{/lambda}}`
+    // to call the lambda with the section's raw, unprocessed source text and
+    // re-parse its result against the delimiters active at that section.
+    pub fn insert_section_lambda<K: StrAllocating>(self, key: K, f: &'a mut dyn FnMut(String) -> String) -> Builder<'a> {
+        let Builder { mut data } = self;
+        data.insert(key.into_string(), SectionLambda(RefCell::new(f)));
+        Builder { data }
+    }
+
+    pub(crate) fn data(&self) -> &HashMap<String, Data<'a>> {
+        &self.data
+    }
+
+    // Finishes a `Builder` used to build one item of a `Vector`, e.g.
+    // `insert_vector("people", |_| vec![Builder::new().insert("name", "Bob").into_data()])`.
+    pub fn into_data(self) -> Data<'a> {
+        Map(self.data)
     }
 
-    pub fn create_data_map<'a>(tags: HashSet<String>, data: HashMap<&'a str, &'a str>) -> HashMap<String, String> {
+    pub fn create_data_map<'b>(tags: HashSet<String>, data: HashMap<&'b str, &'b str>) -> HashMap<String, String> {
         let mut value_map = HashMap::new();
 
         for tag in tags.into_iter() {
-            let datum = data.find_equiv(&tag.as_slice())
+            let datum = data.get(&tag[..])
                 .unwrap_or(&"")
                 .to_string();
             value_map.insert(tag, datum);
@@ -0,0 +1,36 @@
+//! A small Mustache template engine.
+
+use std::collections::HashMap;
+use std::cell::RefCell;
+
+pub use build::Builder;
+pub use self::Data::{Static, Bool, Vector, Map, Lambda, SectionLambda};
+pub use parse::Render;
+
+mod build;
+mod parse;
+
+/// `HashBuilder` predates the `Builder` rename; kept as an alias so existing
+/// call sites, including this crate's own spec tests, don't need to change.
+pub type HashBuilder<'a> = Builder<'a>;
+
+pub trait StrAllocating {
+    fn into_string(self) -> String;
+}
+
+impl StrAllocating for String {
+    fn into_string(self) -> String { self }
+}
+
+impl StrAllocating for &str {
+    fn into_string(self) -> String { self.to_string() }
+}
+
+pub enum Data<'a> {
+    Static(String),
+    Bool(bool),
+    Vector(Vec<Data<'a>>),
+    Map(HashMap<String, Data<'a>>),
+    Lambda(RefCell<&'a mut (dyn FnMut() -> String + 'a)>),
+    SectionLambda(RefCell<&'a mut (dyn FnMut(String) -> String + 'a)>),
+}
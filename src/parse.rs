@@ -0,0 +1,329 @@
+use std::io;
+use std::io::Write;
+use std::collections::HashMap;
+
+use super::{Builder, Data};
+use super::{Static, Bool, Vector, Map, Lambda, SectionLambda};
+
+const DEFAULT_OPEN: &str = "{{";
+const DEFAULT_CLOSE: &str = "}}";
+
+pub trait Render {
+    fn render<W: Write>(&self, template: &str, writer: &mut W) -> io::Result<()>;
+}
+
+impl<'a> Render for Builder<'a> {
+    fn render<W: Write>(&self, template: &str, writer: &mut W) -> io::Result<()> {
+        let mut out = String::new();
+        let nodes = Parser::new(template).parse_until(None);
+        render_nodes(&nodes, &[self.data()], &mut out);
+        writer.write_all(out.as_bytes())
+    }
+}
+
+// Nested Map/Vector sections push a new, innermost scope onto this stack
+// rather than replacing the outer data, so a field not found in the
+// section's own map still falls back to the enclosing context.
+fn lookup<'a, 'd>(scopes: &'d [&'d HashMap<String, Data<'a>>], name: &str) -> Option<&'d Data<'a>> {
+    for scope in scopes.iter().rev() {
+        if let Some(value) = scope.get(name) {
+            return Some(value);
+        }
+    }
+    None
+}
+
+enum Node {
+    Text(String),
+    Var { name: String, escape: bool },
+    // `delim` is the (open, close) pair in effect at this section's tag, so a
+    // section lambda's return value re-parses against the *current*
+    // delimiters rather than always falling back to the default ones.
+    Section { name: String, inverted: bool, raw: String, delim: (String, String), children: Vec<Node> },
+}
+
+struct Parser<'t> {
+    template: &'t str,
+    pos: usize,
+    open: String,
+    close: String,
+    // Where the enclosing section's raw text ends: the start of its
+    // `{{/name}}` tag, or EOF if the template never closes it.
+    last_section_start: usize,
+}
+
+impl<'t> Parser<'t> {
+    fn new(template: &'t str) -> Parser<'t> {
+        Parser::with_delims(template, DEFAULT_OPEN.to_string(), DEFAULT_CLOSE.to_string())
+    }
+
+    fn with_delims(template: &'t str, open: String, close: String) -> Parser<'t> {
+        Parser { template, pos: 0, open, close, last_section_start: 0 }
+    }
+
+    // True when `tag_start..tag_end` is the only non-whitespace content on
+    // its line, meaning a Set-Delimiter tag there shouldn't leave a blank
+    // line behind in the rendered output.
+    fn is_standalone(&self, tag_start: usize, tag_end: usize) -> bool {
+        let line_start = self.template[..tag_start].rfind('\n').map_or(0, |i| i + 1);
+        let before = &self.template[line_start..tag_start];
+        let after = &self.template[tag_end..];
+
+        before.chars().all(|c| c == ' ' || c == '\t') &&
+            (after.is_empty() || after.starts_with('\n'))
+    }
+
+    fn parse_until(&mut self, end_name: Option<&str>) -> Vec<Node> {
+        let mut nodes = Vec::new();
+
+        loop {
+            let open = self.open.clone();
+            let close = self.close.clone();
+            let rest = &self.template[self.pos..];
+
+            let idx = match rest.find(&open[..]) {
+                Some(idx) => idx,
+                None => {
+                    nodes.push(Node::Text(rest.to_string()));
+                    self.pos = self.template.len();
+                    self.last_section_start = self.pos;
+                    return nodes;
+                }
+            };
+
+            let tag_start = self.pos + idx;
+            let after_open = tag_start + open.len();
+
+            if open == DEFAULT_OPEN && close == DEFAULT_CLOSE && self.template[after_open..].starts_with('{') {
+                let body_start = after_open + 1;
+                match self.template[body_start..].find("}}}") {
+                    Some(rel_end) => {
+                        if idx > 0 {
+                            nodes.push(Node::Text(rest[..idx].to_string()));
+                        }
+                        let end = body_start + rel_end;
+                        let name = self.template[body_start..end].trim().to_string();
+                        nodes.push(Node::Var { name, escape: false });
+                        self.pos = end + 3;
+                        continue;
+                    }
+                    None => {
+                        nodes.push(Node::Text(self.template[self.pos..].to_string()));
+                        self.pos = self.template.len();
+                        self.last_section_start = self.pos;
+                        return nodes;
+                    }
+                }
+            }
+
+            if self.template[after_open..].starts_with('=') {
+                let body_start = after_open + 1;
+                let closing = format!("={}", close);
+                match self.template[body_start..].find(&closing[..]) {
+                    Some(rel_end) => {
+                        let end = body_start + rel_end;
+                        let tag_end = end + closing.len();
+                        let spec = self.template[body_start..end].trim();
+                        let mut parts = spec.split_whitespace();
+                        let new_open = parts.next().unwrap_or(&open).to_string();
+                        let new_close = parts.next().unwrap_or(&close).to_string();
+
+                        let standalone = self.is_standalone(tag_start, tag_end);
+
+                        if standalone {
+                            // Drop the tag's own line (leading whitespace included)
+                            // so a Set-Delimiter tag on its own line doesn't leave
+                            // a blank line behind in the rendered output.
+                            let line_start = self.template[..tag_start].rfind('\n').map_or(0, |i| i + 1);
+                            if line_start > self.pos {
+                                nodes.push(Node::Text(self.template[self.pos..line_start].to_string()));
+                            }
+                        } else if idx > 0 {
+                            nodes.push(Node::Text(rest[..idx].to_string()));
+                        }
+
+                        self.open = new_open;
+                        self.close = new_close;
+
+                        self.pos = if standalone && self.template[tag_end..].starts_with('\n') {
+                            tag_end + 1
+                        } else {
+                            tag_end
+                        };
+                        continue;
+                    }
+                    None => {
+                        nodes.push(Node::Text(self.template[self.pos..].to_string()));
+                        self.pos = self.template.len();
+                        self.last_section_start = self.pos;
+                        return nodes;
+                    }
+                }
+            }
+
+            if idx > 0 {
+                nodes.push(Node::Text(rest[..idx].to_string()));
+            }
+
+            let close_idx = match self.template[after_open..].find(&close[..]) {
+                Some(rel) => after_open + rel,
+                None => {
+                    nodes.push(Node::Text(self.template[tag_start..].to_string()));
+                    self.pos = self.template.len();
+                    self.last_section_start = self.pos;
+                    return nodes;
+                }
+            };
+
+            let inner = self.template[after_open..close_idx].trim().to_string();
+            let tag_end = close_idx + close.len();
+
+            match inner.chars().next() {
+                Some('#') => {
+                    let name = inner[1..].trim().to_string();
+                    self.pos = tag_end;
+                    let raw_start = self.pos;
+                    let delim = (self.open.clone(), self.close.clone());
+                    let children = self.parse_until(Some(&name[..]));
+                    let raw = self.template[raw_start..self.last_section_start].to_string();
+                    nodes.push(Node::Section { name, inverted: false, raw, delim, children });
+                }
+                Some('^') => {
+                    let name = inner[1..].trim().to_string();
+                    self.pos = tag_end;
+                    let raw_start = self.pos;
+                    let delim = (self.open.clone(), self.close.clone());
+                    let children = self.parse_until(Some(&name[..]));
+                    let raw = self.template[raw_start..self.last_section_start].to_string();
+                    nodes.push(Node::Section { name, inverted: true, raw, delim, children });
+                }
+                Some('/') => {
+                    if let Some(expected) = end_name {
+                        debug_assert_eq!(inner[1..].trim(), expected);
+                    }
+                    self.last_section_start = tag_start;
+                    self.pos = tag_end;
+                    return nodes;
+                }
+                Some('&') => {
+                    let name = inner[1..].trim().to_string();
+                    nodes.push(Node::Var { name, escape: false });
+                    self.pos = tag_end;
+                }
+                Some('!') => {
+                    self.pos = tag_end;
+                }
+                _ => {
+                    nodes.push(Node::Var { name: inner, escape: true });
+                    self.pos = tag_end;
+                }
+            }
+        }
+    }
+}
+
+fn render_nodes<'a>(nodes: &[Node], scopes: &[&HashMap<String, Data<'a>>], out: &mut String) {
+    for node in nodes.iter() {
+        match *node {
+            Node::Text(ref s) => out.push_str(&s[..]),
+            Node::Var { ref name, escape } => render_var(&name[..], escape, scopes, out),
+            Node::Section { ref name, inverted, ref raw, ref delim, ref children } =>
+                render_section(&name[..], inverted, &raw[..], delim, children, scopes, out),
+        }
+    }
+}
+
+fn render_var<'a>(name: &str, escape: bool, scopes: &[&HashMap<String, Data<'a>>], out: &mut String) {
+    match lookup(scopes, name) {
+        Some(Static(s)) => push_maybe_escaped(&s[..], escape, out),
+        Some(Bool(b)) => push_maybe_escaped(&b.to_string()[..], escape, out),
+        Some(Lambda(cell)) => {
+            let rendered = {
+                let mut f = cell.borrow_mut();
+                (*f)()
+            };
+            let mut expanded = String::new();
+            let nodes = Parser::new(&rendered[..]).parse_until(None);
+            render_nodes(&nodes, scopes, &mut expanded);
+            push_maybe_escaped(&expanded[..], escape, out);
+        }
+        _ => {}
+    }
+}
+
+fn render_section<'a>(name: &str, inverted: bool, raw: &str, delim: &(String, String), children: &[Node], scopes: &[&HashMap<String, Data<'a>>], out: &mut String) {
+    let truthy = is_truthy(lookup(scopes, name));
+
+    if inverted {
+        if !truthy {
+            render_nodes(children, scopes, out);
+        }
+        return;
+    }
+
+    if !truthy {
+        return;
+    }
+
+    match lookup(scopes, name) {
+        Some(SectionLambda(cell)) => {
+            let rendered = {
+                let mut f = cell.borrow_mut();
+                (*f)(raw.to_string())
+            };
+            let (ref open, ref close) = *delim;
+            let nodes = Parser::with_delims(&rendered[..], open.clone(), close.clone()).parse_until(None);
+            render_nodes(&nodes, scopes, out);
+        }
+        Some(Map(map)) => {
+            let mut inner_scopes = scopes.to_vec();
+            inner_scopes.push(map);
+            render_nodes(children, &inner_scopes, out);
+        }
+        Some(Vector(items)) => {
+            for item in items.iter() {
+                match *item {
+                    Map(ref map) => {
+                        let mut inner_scopes = scopes.to_vec();
+                        inner_scopes.push(map);
+                        render_nodes(children, &inner_scopes, out);
+                    }
+                    _ => render_nodes(children, scopes, out),
+                }
+            }
+        }
+        _ => render_nodes(children, scopes, out),
+    }
+}
+
+// A lambda bound to a section tag (including an inverted one) is always
+// truthy, regardless of what it returns: its presence, not its return value,
+// decides whether the section renders.
+fn is_truthy(value: Option<&Data>) -> bool {
+    match value {
+        None => false,
+        Some(Bool(b)) => *b,
+        Some(Vector(items)) => !items.is_empty(),
+        Some(Lambda(_)) => true,
+        Some(SectionLambda(_)) => true,
+        _ => true,
+    }
+}
+
+fn push_maybe_escaped(s: &str, escape: bool, out: &mut String) {
+    if !escape {
+        out.push_str(s);
+        return;
+    }
+
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+}
@@ -0,0 +1,151 @@
+extern crate rustache;
+extern crate serde_json;
+
+use rustache::{HashBuilder, Render};
+use std::io::Cursor;
+use serde_json::Value;
+
+// Loads the official mustache spec's ~lambdas.json fixtures and runs every
+// case through `render`, instead of hand-transcribing each case as its own
+// #[test] (see test_spec_lambdas.rs). Dropping in a newer copy of the spec
+// JSON picks up upstream's `raku`/`lisp`/`pwsh` variants without touching
+// this file; a new case also needs a matching closure registered below (and,
+// for a section case, its name added to `is_section_case`), or the run panics.
+
+struct SpecCase {
+    name: String,
+    desc: String,
+    data: Value,
+    template: String,
+    expected: String,
+}
+
+fn load_fixtures(raw: &str) -> Vec<SpecCase> {
+    let fixtures: Value = serde_json::from_str(raw).unwrap();
+    let tests = fixtures.get("tests").unwrap().as_array().unwrap();
+
+    tests.iter().map(|case| {
+        SpecCase {
+            name: case.get("name").unwrap().as_str().unwrap().to_string(),
+            desc: case.get("desc").unwrap().as_str().unwrap().to_string(),
+            data: case.get("data").unwrap().clone(),
+            template: case.get("template").unwrap().as_str().unwrap().to_string(),
+            expected: case.get("expected").unwrap().as_str().unwrap().to_string(),
+        }
+    }).collect()
+}
+
+// Whether a case's lambda is called through `{{#lambda}}`/`{{^lambda}}`
+// (arity-1, receives the raw section text) rather than `{{lambda}}`
+// (arity-0). The spec's own case names are stable enough to key off of.
+fn is_section_case(name: &str) -> bool {
+    matches!(name, "Section" | "Section - Expansion" | "Section - Alternate Delimiters" |
+        "Section - Multiple Calls" | "Inverted Section")
+}
+
+// The spec encodes a lambda's behavior as Ruby/Perl/JS/... source under a
+// `{ "__tag__": "code", ... }` value, which this crate obviously can't
+// execute. Instead each case name is mapped here to the equivalent Rust
+// closure, built fresh per call so the "Multiple Calls" cases get their own
+// unshared counter on every run.
+fn interpolation_lambda_for_case(name: &str) -> Box<dyn FnMut() -> String> {
+    match name {
+        "Interpolation" =>
+            Box::new(|| "world".to_string()),
+        "Interpolation - Expansion" =>
+            Box::new(|| "{{planet}}".to_string()),
+        "Interpolation - Alternate Delimiters" =>
+            Box::new(|| "|planet| => {{planet}}".to_string()),
+        "Interpolation - Multiple Calls" => {
+            let mut calls = 0u32;
+            Box::new(move || {
+                calls += 1;
+                calls.to_string()
+            })
+        }
+        "Escaping" =>
+            Box::new(|| ">".to_string()),
+        other => panic!("no interpolation lambda registered for spec case `{}`", other),
+    }
+}
+
+fn section_lambda_for_case(name: &str) -> Box<dyn FnMut(String) -> String> {
+    match name {
+        "Section" =>
+            Box::new(|txt: String| {
+                if &txt[..] == "{{x}}" { "yes".to_string() } else { "no".to_string() }
+            }),
+        "Section - Expansion" =>
+            Box::new(|txt: String| {
+                let mut result = txt.clone();
+                result.push_str("{{planet}}");
+                result.push_str(&txt[..]);
+                result
+            }),
+        "Section - Alternate Delimiters" =>
+            Box::new(|txt: String| {
+                let mut result = txt.clone();
+                result.push_str("{{planet}} => |planet|");
+                result.push_str(&txt[..]);
+                result
+            }),
+        "Section - Multiple Calls" =>
+            Box::new(|txt: String| {
+                let mut result = "__".to_string();
+                result.push_str(&txt[..]);
+                result.push_str("__");
+                result
+            }),
+        "Inverted Section" =>
+            Box::new(|_: String| "false".to_string()),
+        other => panic!("no section lambda registered for spec case `{}`", other),
+    }
+}
+
+fn non_lambda_fields<'a>(case: &SpecCase, builder: HashBuilder<'a>) -> HashBuilder<'a> {
+    let mut builder = builder;
+
+    for (key, value) in case.data.as_object().unwrap().iter() {
+        if &key[..] == "lambda" {
+            continue;
+        }
+
+        builder = match *value {
+            Value::String(ref s) => builder.insert(&key[..], &s[..]),
+            Value::Bool(b) => builder.insert_bool(&key[..], b),
+            ref other => panic!("fixture data for `{}` has unsupported shape: {:?}", key, other),
+        };
+    }
+
+    builder
+}
+
+#[test]
+fn test_spec_lambdas_from_fixtures() {
+    let raw = include_str!("fixtures/lambdas.json");
+    let mut mismatches = Vec::new();
+
+    for case in load_fixtures(raw).iter() {
+        let mut rv = Cursor::new(Vec::new());
+
+        if is_section_case(&case.name[..]) {
+            let mut lambda = section_lambda_for_case(&case.name[..]);
+            let data = non_lambda_fields(case, HashBuilder::new()).insert_section_lambda("lambda", &mut lambda);
+            data.render(&case.template[..], &mut rv).unwrap();
+        } else {
+            let mut lambda = interpolation_lambda_for_case(&case.name[..]);
+            let data = non_lambda_fields(case, HashBuilder::new()).insert_lambda("lambda", &mut lambda);
+            data.render(&case.template[..], &mut rv).unwrap();
+        }
+
+        let actual = String::from_utf8(rv.into_inner()).unwrap();
+
+        if actual != case.expected {
+            mismatches.push(format!(
+                "case `{}` ({}): expected {:?}, got {:?}",
+                case.name, case.desc, case.expected, actual));
+        }
+    }
+
+    assert!(mismatches.is_empty(), "{} case(s) failed:\n{}", mismatches.len(), mismatches.join("\n"));
+}
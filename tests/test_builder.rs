@@ -0,0 +1,31 @@
+extern crate rustache;
+
+use rustache::{HashBuilder, Render};
+use std::io::Cursor;
+
+#[test]
+fn test_section_over_insert_map() {
+    let data = HashBuilder::new()
+                .insert_map("person", |builder| builder.insert("name", "Bob"));
+    let mut rv = Cursor::new(Vec::new());
+
+    data.render("{{#person}}Hello {{name}}{{/person}}", &mut rv).unwrap();
+
+    assert_eq!("Hello Bob".to_string(), String::from_utf8(rv.into_inner()).unwrap());
+}
+
+#[test]
+fn test_section_over_insert_vector_of_maps() {
+    let data = HashBuilder::new()
+                .insert_vector("people", |_| {
+                    vec![
+                        HashBuilder::new().insert("name", "Bob").into_data(),
+                        HashBuilder::new().insert("name", "Alice").into_data(),
+                    ]
+                });
+    let mut rv = Cursor::new(Vec::new());
+
+    data.render("{{#people}}{{name}} {{/people}}", &mut rv).unwrap();
+
+    assert_eq!("Bob Alice ".to_string(), String::from_utf8(rv.into_inner()).unwrap());
+}
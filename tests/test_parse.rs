@@ -0,0 +1,14 @@
+extern crate rustache;
+
+use rustache::{HashBuilder, Render};
+use std::io::Cursor;
+
+#[test]
+fn test_unclosed_section_renders_to_eof_without_panicking() {
+    let data = HashBuilder::new().insert_bool("x", true);
+    let mut rv = Cursor::new(Vec::new());
+
+    data.render("{{#x}}unterminated", &mut rv).unwrap();
+
+    assert_eq!("unterminated".to_string(), String::from_utf8(rv.into_inner()).unwrap());
+}
@@ -17,7 +17,7 @@ use std::io::Cursor;
 //     expected: "Hello, world!"
 #[test]
 fn test_spec_lambdas_interpolation() {
-    let mut f = |_| { "world".to_string() };
+    let mut f = || "world".to_string();
     let data = HashBuilder::new()
                 .insert_lambda("lambda", &mut f);
     let mut rv = Cursor::new(Vec::new());
@@ -42,7 +42,7 @@ fn test_spec_lambdas_interpolation() {
 //     expected: "Hello, world!"
 #[test]
 fn test_spec_lambdas_interpolation_expansion() {
-    let mut f = |_| { "{{planet}}".to_string() };
+    let mut f = || "{{planet}}".to_string();
     let data = HashBuilder::new()
                     .insert("planet", "world")
                     .insert_lambda("lambda", &mut f);
@@ -66,19 +66,18 @@ fn test_spec_lambdas_interpolation_expansion() {
 //         clojure: '(fn [] "|planet| => {{planet}}")'
 //     template: "{{= | | =}}\nHello, (|&lambda|)!"
 //     expected: "Hello, (|planet| => world)!"
-// #[test]
-// fn test_spec_lambdas_interpolation_alternate_delimeters() {
-//     let data = HashBuilder::new()
-//                 .insert("planet", "world")
-//                 .insert_lambda("lambda", |_| {
-//                     "|planet| => {{planet}}".to_string()
-//                 });
-//     let mut rv = Cursor::new(Vec::new());
+#[test]
+fn test_spec_lambdas_interpolation_alternate_delimeters() {
+    let mut f = || "|planet| => {{planet}}".to_string();
+    let data = HashBuilder::new()
+                .insert("planet", "world")
+                .insert_lambda("lambda", &mut f);
+    let mut rv = Cursor::new(Vec::new());
 
-//     data.render("{{= | | =}}\nHello, (|&lambda|)!", &mut rv).unwrap();
+    data.render("{{= | | =}}\nHello, (|&lambda|)!", &mut rv).unwrap();
 
-//     assert_eq!("Hello, (|planet| => world)!".to_string(), String::from_utf8(rv.into_inner()).unwrap());
-// }
+    assert_eq!("Hello, (|planet| => world)!".to_string(), String::from_utf8(rv.into_inner()).unwrap());
+}
 
 //   - name: Interpolation - Multiple Calls
 //     desc: Interpolated lambdas should not be cached.
@@ -95,7 +94,7 @@ fn test_spec_lambdas_interpolation_expansion() {
 #[test]
 fn test_spec_lambdas_interpolation_multiple_calls() {
     let mut calls = 0;
-    let mut f = |_| {
+    let mut f = || {
         calls += 1;
         calls.to_string()
     };
@@ -122,7 +121,7 @@ fn test_spec_lambdas_interpolation_multiple_calls() {
 //     expected: "<&gt;>"
 #[test]
 fn test_spec_lambdas_escaping() {
-    let mut f = |_| { ">".to_string() };
+    let mut f = || ">".to_string();
     let data = HashBuilder::new()
                 .insert_lambda("lambda", &mut f);
     let mut rv = Cursor::new(Vec::new());
@@ -156,7 +155,7 @@ fn test_spec_lambdas_section() {
                 };
     let data = HashBuilder::new()
                 .insert("x", "Error!")
-                .insert_lambda("lambda", &mut f);
+                .insert_section_lambda("lambda", &mut f);
     let mut rv = Cursor::new(Vec::new());
 
     data.render("<{{#lambda}}{{x}}{{/lambda}}>", &mut rv).unwrap();
@@ -187,7 +186,7 @@ fn test_spec_lambdas_section_expansion() {
                 };
     let data = HashBuilder::new()
                 .insert("planet", "Earth")
-                .insert_lambda("lambda", &mut f);
+                .insert_section_lambda("lambda", &mut f);
     let mut rv = Cursor::new(Vec::new());
 
     data.render("<{{#lambda}}-{{/lambda}}>", &mut rv).unwrap();
@@ -208,22 +207,23 @@ fn test_spec_lambdas_section_expansion() {
 //         clojure: '(fn [text] (str text "{{planet}} => |planet|" text))'
 //     template: "{{= | | =}}<|#lambda|-|/lambda|>"
 //     expected: "<-{{planet}} => Earth->"
-// #[test]
-// fn test_spec_lambdas_section_alternate_delimeters() {
-//     let data = HashBuilder::new()
-//                 .insert("planet", "Earth")
-//                 .insert_lambda("lambda", |txt| {
-//                     let mut result = txt.to_string();
-//                     result.push_str("{{planet}} => |planet|");
-//                     result.push_str(txt.as_slice());
-//                     result
-//                 });
-//     let mut rv = Cursor::new(Vec::new());
-
-//     data.render_from_hb("{{= | | =}}<|#lambda|-|/lambda|>", &mut rv).unwrap();
-
-//     assert_eq!("<-{{planet}} => Earth->".to_string(), String::from_utf8(rv.into_inner()).unwrap());
-// }
+#[test]
+fn test_spec_lambdas_section_alternate_delimeters() {
+    let mut f = |txt: String| {
+                    let mut result = txt.clone();
+                    result.push_str("{{planet}} => |planet|");
+                    result.push_str(&txt[..]);
+                    result
+                };
+    let data = HashBuilder::new()
+                .insert("planet", "Earth")
+                .insert_section_lambda("lambda", &mut f);
+    let mut rv = Cursor::new(Vec::new());
+
+    data.render("{{= | | =}}<|#lambda|-|/lambda|>", &mut rv).unwrap();
+
+    assert_eq!("<-{{planet}} => Earth->".to_string(), String::from_utf8(rv.into_inner()).unwrap());
+}
 
 //   - name: Section - Multiple Calls
 //     desc: Lambdas used for sections should not be cached.
@@ -246,7 +246,7 @@ fn test_spec_lambdas_section_multiple_calls() {
                     result
                 };
     let data = HashBuilder::new()
-                .insert_lambda("lambda", &mut f);
+                .insert_section_lambda("lambda", &mut f);
     let mut rv = Cursor::new(Vec::new());
 
     data.render("{{#lambda}}FILE{{/lambda}} != {{#lambda}}LINE{{/lambda}}", &mut rv).unwrap();
@@ -269,10 +269,10 @@ fn test_spec_lambdas_section_multiple_calls() {
 //     expected: "<>"
 #[test]
 fn test_spec_lambdas_inverted_section() {
-    let mut f = |_| { "false".to_string() };
+    let mut f = |_: String| "false".to_string();
     let data = HashBuilder::new()
                 .insert("static", "static")
-                .insert_lambda("lambda", &mut f);
+                .insert_section_lambda("lambda", &mut f);
     let mut rv = Cursor::new(Vec::new());
 
     data.render("<{{^lambda}}{{static}}{{/lambda}}>", &mut rv).unwrap();